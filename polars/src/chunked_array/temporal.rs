@@ -1,6 +1,8 @@
 //! Traits and utilities for temporal data.
 use crate::prelude::*;
-use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use chrono::{
+    DateTime, Datelike, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike, Utc,
+};
 
 // Conversion extracted from:
 // https://docs.rs/arrow/1.0.0/src/arrow/array/array.rs.html#589
@@ -14,46 +16,46 @@ const MICROSECONDS_IN_SECOND: i64 = 1_000_000;
 /// Number of nanoseconds in a second
 const NANOSECONDS_IN_SECOND: i64 = 1_000_000_000;
 
-pub(crate) fn date32_as_datetime(v: i32) -> NaiveDateTime {
-    NaiveDateTime::from_timestamp(v as i64 * SECONDS_IN_DAY, 0)
+pub(crate) fn date32_as_datetime(v: i32) -> Option<NaiveDateTime> {
+    NaiveDateTime::from_timestamp_opt(v as i64 * SECONDS_IN_DAY, 0)
 }
 
-pub(crate) fn date64_as_datetime(v: i64) -> NaiveDateTime {
-    NaiveDateTime::from_timestamp(
+pub(crate) fn date64_as_datetime(v: i64) -> Option<NaiveDateTime> {
+    NaiveDateTime::from_timestamp_opt(
         // extract seconds from milliseconds
-        v / MILLISECONDS_IN_SECOND,
+        v.div_euclid(MILLISECONDS_IN_SECOND),
         // discard extracted seconds and convert milliseconds to nanoseconds
-        (v % MILLISECONDS_IN_SECOND * MICROSECONDS_IN_SECOND) as u32,
+        (v.rem_euclid(MILLISECONDS_IN_SECOND) * MICROSECONDS_IN_SECOND) as u32,
     )
 }
 
-pub(crate) fn timestamp_nanoseconds_as_datetime(v: i64) -> NaiveDateTime {
+pub(crate) fn timestamp_nanoseconds_as_datetime(v: i64) -> Option<NaiveDateTime> {
     // some nanoseconds will be truncated down as integer division rounds downwards
-    let seconds = v / 1_000_000_000;
+    let seconds = v.div_euclid(1_000_000_000);
     // we can use that to compute the remaining nanoseconds
-    let nanoseconds = (v - (seconds * 1_000_000_000)) as u32;
+    let nanoseconds = v.rem_euclid(1_000_000_000) as u32;
 
-    NaiveDateTime::from_timestamp(seconds, nanoseconds)
+    NaiveDateTime::from_timestamp_opt(seconds, nanoseconds)
 }
 
-pub(crate) fn timestamp_microseconds_as_datetime(v: i64) -> NaiveDateTime {
+pub(crate) fn timestamp_microseconds_as_datetime(v: i64) -> Option<NaiveDateTime> {
     // see nanoseconds for the logic
-    let seconds = v / 1_000_000;
-    let microseconds = (v - (seconds * 1_000_000)) as u32;
+    let seconds = v.div_euclid(1_000_000);
+    let microseconds = v.rem_euclid(1_000_000);
 
-    NaiveDateTime::from_timestamp(seconds, microseconds)
+    NaiveDateTime::from_timestamp_opt(seconds, (microseconds * 1000) as u32)
 }
 
-pub(crate) fn timestamp_milliseconds_as_datetime(v: i64) -> NaiveDateTime {
+pub(crate) fn timestamp_milliseconds_as_datetime(v: i64) -> Option<NaiveDateTime> {
     // see nanoseconds for the logic
-    let seconds = v / 1000;
-    let milliseconds = (v - (seconds * 1000)) as u32;
+    let seconds = v.div_euclid(1000);
+    let milliseconds = v.rem_euclid(1000);
 
-    NaiveDateTime::from_timestamp(seconds, milliseconds)
+    NaiveDateTime::from_timestamp_opt(seconds, (milliseconds * 1_000_000) as u32)
 }
 
-pub(crate) fn timestamp_seconds_as_datetime(seconds: i64) -> NaiveDateTime {
-    NaiveDateTime::from_timestamp(seconds, 0)
+pub(crate) fn timestamp_seconds_as_datetime(seconds: i64) -> Option<NaiveDateTime> {
+    NaiveDateTime::from_timestamp_opt(seconds, 0)
 }
 
 // date64 is number of milliseconds since the Unix Epoch
@@ -103,48 +105,127 @@ pub(crate) fn naive_time_to_time32_milliseconds(v: &NaiveTime) -> i32 {
 pub(crate) fn naive_time_to_time32_seconds(v: &NaiveTime) -> i32 {
     v.hour() as i32 * 3600 + v.minute() as i32 * 60 + v.second() as i32 + v.nanosecond() as i32
 }
-pub(crate) fn time64_nanosecond_as_time(v: i64) -> NaiveTime {
-    NaiveTime::from_num_seconds_from_midnight(
+pub(crate) fn time64_nanosecond_as_time(v: i64) -> Option<NaiveTime> {
+    NaiveTime::from_num_seconds_from_midnight_opt(
         // extract seconds from nanoseconds
-        (v / NANOSECONDS_IN_SECOND) as u32,
+        v.div_euclid(NANOSECONDS_IN_SECOND) as u32,
         // discard extracted seconds
-        (v % NANOSECONDS_IN_SECOND) as u32,
+        v.rem_euclid(NANOSECONDS_IN_SECOND) as u32,
     )
 }
 
-pub(crate) fn time64_microsecond_as_time(v: i64) -> NaiveTime {
-    NaiveTime::from_num_seconds_from_midnight(
+pub(crate) fn time64_microsecond_as_time(v: i64) -> Option<NaiveTime> {
+    NaiveTime::from_num_seconds_from_midnight_opt(
         // extract seconds from microseconds
-        (v / MICROSECONDS_IN_SECOND) as u32,
+        v.div_euclid(MICROSECONDS_IN_SECOND) as u32,
         // discard extracted seconds and convert microseconds to
         // nanoseconds
-        (v % MICROSECONDS_IN_SECOND * MILLISECONDS_IN_SECOND) as u32,
+        (v.rem_euclid(MICROSECONDS_IN_SECOND) * MILLISECONDS_IN_SECOND) as u32,
     )
 }
 
-pub(crate) fn time32_second_as_time(v: i32) -> NaiveTime {
-    NaiveTime::from_num_seconds_from_midnight(v as u32, 0)
+pub(crate) fn time32_second_as_time(v: i32) -> Option<NaiveTime> {
+    NaiveTime::from_num_seconds_from_midnight_opt(v as u32, 0)
 }
 
-pub(crate) fn time32_millisecond_as_time(v: i32) -> NaiveTime {
-    let v = v as u32;
-    NaiveTime::from_num_seconds_from_midnight(
+pub(crate) fn time32_millisecond_as_time(v: i32) -> Option<NaiveTime> {
+    let v = v as i64;
+    NaiveTime::from_num_seconds_from_midnight_opt(
         // extract seconds from milliseconds
-        v / MILLISECONDS_IN_SECOND as u32,
+        v.div_euclid(MILLISECONDS_IN_SECOND) as u32,
         // discard extracted seconds and convert milliseconds to
         // nanoseconds
-        v % MILLISECONDS_IN_SECOND as u32 * MICROSECONDS_IN_SECOND as u32,
+        (v.rem_euclid(MILLISECONDS_IN_SECOND) * MICROSECONDS_IN_SECOND) as u32,
     )
 }
 
 pub fn unix_time() -> NaiveDateTime {
-    NaiveDateTime::from_timestamp(0, 0)
+    NaiveDateTime::from_timestamp_opt(0, 0).unwrap()
 }
 
 pub trait FromNaiveTime<T, N> {
     fn new_from_naive_time(name: &str, v: &[N]) -> Self;
 
     fn parse_from_str_slice(name: &str, v: &[&str], fmt: &str) -> Self;
+
+    /// Like `parse_from_str_slice`, but instead of silently dropping rows that fail to parse to
+    /// null, collects their indices and offending strings and returns a `PolarsError` describing
+    /// what failed.
+    fn parse_from_str_slice_strict(name: &str, v: &[&str], fmt: &str) -> Result<Self>
+    where
+        Self: Sized;
+
+    /// Tries each of [`CANDIDATE_TIME_FORMATS`] against a sample of `v`'s non-null values, locks
+    /// onto the first format that parses all of them, then parses the whole slice with it.
+    /// Returns the resulting chunked array together with the detected format so callers can reuse
+    /// it, or `None` if no candidate format matched the sample.
+    fn parse_from_str_slice_infer(name: &str, v: &[&str]) -> Option<(Self, &'static str)>
+    where
+        Self: Sized,
+    {
+        let fmt = infer_format(v, CANDIDATE_TIME_FORMATS, parse_naive_time_from_str)?;
+        Some((Self::parse_from_str_slice(name, v, fmt), fmt))
+    }
+}
+
+/// Candidate formats tried, in order, by [`FromNaiveTime::parse_from_str_slice_infer`].
+const CANDIDATE_TIME_FORMATS: &[&str] = &["%H:%M:%S%.f", "%H:%M:%S", "%H:%M"];
+
+/// Number of leading non-null values checked against each candidate format.
+const FORMAT_INFERENCE_SAMPLE_SIZE: usize = 10;
+
+/// Tries each of `candidates`, in order, against a sample of `v`'s non-null values using
+/// `try_parse`, returning the first format that parses the whole sample.
+fn infer_format<'a, T>(
+    v: &[&str],
+    candidates: &[&'a str],
+    try_parse: impl Fn(&str, &str) -> Option<T>,
+) -> Option<&'a str> {
+    let sample: Vec<&str> = v
+        .iter()
+        .filter(|s| !s.is_empty())
+        .take(FORMAT_INFERENCE_SAMPLE_SIZE)
+        .copied()
+        .collect();
+    if sample.is_empty() {
+        return None;
+    }
+    candidates
+        .iter()
+        .find(|fmt| sample.iter().all(|s| try_parse(s, fmt).is_some()))
+        .copied()
+}
+
+/// Shared body of every `parse_from_str_slice_strict`: parses each of `v` with `try_parse`,
+/// collecting the index and offending string of every failure, and fails the whole slice with a
+/// single `PolarsError` describing all of them rather than silently nulling out bad rows.
+fn strict_parse_slice<T>(
+    v: &[&str],
+    fmt: &str,
+    try_parse: impl Fn(&str, &str) -> Option<T>,
+) -> Result<Vec<Option<T>>> {
+    let mut failures = Vec::new();
+    let parsed: Vec<_> = v
+        .iter()
+        .enumerate()
+        .map(|(i, s)| {
+            let t = try_parse(s, fmt);
+            if t.is_none() {
+                failures.push((i, (*s).to_string()));
+            }
+            t
+        })
+        .collect();
+    if !failures.is_empty() {
+        return Err(PolarsError::Other(format!(
+            "failed to parse {} of {} value(s) with format `{}`: {:?}",
+            failures.len(),
+            v.len(),
+            fmt,
+            failures
+        )));
+    }
+    Ok(parsed)
 }
 
 fn parse_naive_time_from_str(s: &str, fmt: &str) -> Option<NaiveTime> {
@@ -166,6 +247,14 @@ macro_rules! impl_from_naive_time {
                         .map(|s| parse_naive_time_from_str(s, fmt).as_ref().map($func)),
                 )
             }
+
+            fn parse_from_str_slice_strict(name: &str, v: &[&str], fmt: &str) -> Result<Self> {
+                let parsed = strict_parse_slice(v, fmt, parse_naive_time_from_str)?;
+                Ok(ChunkedArray::new_from_opt_iter(
+                    name,
+                    parsed.iter().map(|opt| opt.as_ref().map($func)),
+                ))
+            }
         }
     };
 }
@@ -199,7 +288,7 @@ macro_rules! impl_as_naivetime {
     ($ca:ty, $fun:ident) => {
         impl AsNaiveTime for $ca {
             fn as_naive_time(&self) -> Vec<Option<NaiveTime>> {
-                self.into_iter().map(|opt_t| opt_t.map($fun)).collect()
+                self.into_iter().map(|opt_t| opt_t.and_then($fun)).collect()
             }
         }
     };
@@ -211,15 +300,46 @@ impl_as_naivetime!(Time64NanosecondChunked, time64_nanosecond_as_time);
 impl_as_naivetime!(Time64MicrosecondChunked, time64_microsecond_as_time);
 
 fn parse_naive_datetime_from_str(s: &str, fmt: &str) -> Option<NaiveDateTime> {
-    NaiveDateTime::parse_from_str(s, fmt).ok()
+    // `fmt` may be a date-only format (e.g. the bare `"%Y-%m-%d"` candidate in
+    // `CANDIDATE_DATETIME_FORMATS`), which `NaiveDateTime::parse_from_str` rejects outright since
+    // there's no time component to parse. Fall back to `NaiveDate` and promote to midnight so
+    // date-only formats still work for this datetime-typed path.
+    NaiveDateTime::parse_from_str(s, fmt)
+        .ok()
+        .or_else(|| NaiveDate::parse_from_str(s, fmt).ok().map(|d| d.and_hms(0, 0, 0)))
 }
 
 pub trait FromNaiveDateTime<T, N> {
     fn new_from_naive_datetime(name: &str, v: &[N]) -> Self;
 
     fn parse_from_str_slice(name: &str, v: &[&str], fmt: &str) -> Self;
+
+    /// See [`FromNaiveTime::parse_from_str_slice_strict`].
+    fn parse_from_str_slice_strict(name: &str, v: &[&str], fmt: &str) -> Result<Self>
+    where
+        Self: Sized;
+
+    /// Tries each of [`CANDIDATE_DATETIME_FORMATS`] against a sample of `v`'s non-null values,
+    /// locks onto the first format that parses all of them, then parses the whole slice with it.
+    /// Returns the resulting chunked array together with the detected format so callers can reuse
+    /// it, or `None` if no candidate format matched the sample.
+    fn parse_from_str_slice_infer(name: &str, v: &[&str]) -> Option<(Self, &'static str)>
+    where
+        Self: Sized,
+    {
+        let fmt = infer_format(v, CANDIDATE_DATETIME_FORMATS, parse_naive_datetime_from_str)?;
+        Some((Self::parse_from_str_slice(name, v, fmt), fmt))
+    }
 }
 
+/// Candidate formats tried, in order, by [`FromNaiveDateTime::parse_from_str_slice_infer`]. The
+/// unambiguous ISO-like variants are tried before the bare date so the more specific match wins.
+const CANDIDATE_DATETIME_FORMATS: &[&str] = &[
+    "%Y-%m-%dT%H:%M:%S",
+    "%Y-%m-%d %H:%M:%S",
+    "%Y-%m-%d",
+];
+
 macro_rules! impl_from_naive_datetime {
     ($arrowtype:ident, $chunkedtype:ident, $func:ident) => {
         impl FromNaiveDateTime<$arrowtype, NaiveDateTime> for $chunkedtype {
@@ -235,6 +355,14 @@ macro_rules! impl_from_naive_datetime {
                         .map(|s| parse_naive_datetime_from_str(s, fmt).as_ref().map($func)),
                 )
             }
+
+            fn parse_from_str_slice_strict(name: &str, v: &[&str], fmt: &str) -> Result<Self> {
+                let parsed = strict_parse_slice(v, fmt, parse_naive_datetime_from_str)?;
+                Ok(ChunkedArray::new_from_opt_iter(
+                    name,
+                    parsed.iter().map(|opt| opt.as_ref().map($func)),
+                ))
+            }
         }
     };
 }
@@ -265,8 +393,28 @@ pub trait FromNaiveDate<T, N> {
     fn new_from_naive_date(name: &str, v: &[N]) -> Self;
 
     fn parse_from_str_slice(name: &str, v: &[&str], fmt: &str) -> Self;
+
+    /// See [`FromNaiveTime::parse_from_str_slice_strict`].
+    fn parse_from_str_slice_strict(name: &str, v: &[&str], fmt: &str) -> Result<Self>
+    where
+        Self: Sized;
+
+    /// Tries each of [`CANDIDATE_DATE_FORMATS`] against a sample of `v`'s non-null values, locks
+    /// onto the first format that parses all of them, then parses the whole slice with it.
+    /// Returns the resulting chunked array together with the detected format so callers can reuse
+    /// it, or `None` if no candidate format matched the sample.
+    fn parse_from_str_slice_infer(name: &str, v: &[&str]) -> Option<(Self, &'static str)>
+    where
+        Self: Sized,
+    {
+        let fmt = infer_format(v, CANDIDATE_DATE_FORMATS, parse_naive_date_from_str)?;
+        Some((Self::parse_from_str_slice(name, v, fmt), fmt))
+    }
 }
 
+/// Candidate formats tried, in order, by [`FromNaiveDate::parse_from_str_slice_infer`].
+const CANDIDATE_DATE_FORMATS: &[&str] = &["%Y-%m-%d", "%Y/%m/%d"];
+
 fn naive_date_to_date32(nd: NaiveDate, unix_time: NaiveDate) -> i32 {
     nd.signed_duration_since(unix_time).num_days() as i32
 }
@@ -302,6 +450,17 @@ impl FromNaiveDate<Date32Type, NaiveDate> for Date32Chunked {
             }),
         )
     }
+
+    fn parse_from_str_slice_strict(name: &str, v: &[&str], fmt: &str) -> Result<Self> {
+        let unix_date = unix_time_naive_date();
+        let parsed = strict_parse_slice(v, fmt, parse_naive_date_from_str)?;
+        Ok(ChunkedArray::new_from_opt_iter(
+            name,
+            parsed
+                .iter()
+                .map(|opt| opt.as_ref().map(|v| naive_date_to_date32(*v, unix_date))),
+        ))
+    }
 }
 
 pub trait AsNaiveDateTime {
@@ -312,7 +471,7 @@ macro_rules! impl_as_naive_datetime {
     ($ca:ty, $fun:ident) => {
         impl AsNaiveDateTime for $ca {
             fn as_naive_datetime(&self) -> Vec<Option<NaiveDateTime>> {
-                self.into_iter().map(|opt_t| opt_t.map($fun)).collect()
+                self.into_iter().map(|opt_t| opt_t.and_then($fun)).collect()
             }
         }
     };
@@ -342,17 +501,110 @@ impl AsNaiveDate for Date32Chunked {
     fn as_naive_date(&self) -> Vec<Option<NaiveDate>> {
         self.into_iter()
             .map(|opt_t| {
-                opt_t.map(|v| {
-                    let dt = date32_as_datetime(v);
-                    NaiveDate::from_ymd(dt.year(), dt.month(), dt.day())
+                opt_t.and_then(|v| {
+                    date32_as_datetime(v).map(|dt| NaiveDate::from_ymd(dt.year(), dt.month(), dt.day()))
                 })
             })
             .collect()
     }
 }
 
+pub trait AsStrftime {
+    /// Format the chunked array's values using a chrono-compatible format string,
+    /// producing a `Utf8Chunked`. Nulls (and values that fail to convert, e.g.
+    /// out-of-range timestamps) are preserved as nulls.
+    fn as_strftime(&self, fmt: &str) -> Utf8Chunked;
+}
+
+macro_rules! impl_as_strftime_time {
+    ($ca:ty, $fun:ident) => {
+        impl AsStrftime for $ca {
+            fn as_strftime(&self, fmt: &str) -> Utf8Chunked {
+                ChunkedArray::new_from_opt_iter(
+                    self.name(),
+                    self.into_iter()
+                        .map(|opt_t| opt_t.and_then($fun).map(|t| t.format(fmt).to_string())),
+                )
+            }
+        }
+    };
+}
+
+impl_as_strftime_time!(Time32SecondChunked, time32_second_as_time);
+impl_as_strftime_time!(Time32MillisecondChunked, time32_millisecond_as_time);
+impl_as_strftime_time!(Time64NanosecondChunked, time64_nanosecond_as_time);
+impl_as_strftime_time!(Time64MicrosecondChunked, time64_microsecond_as_time);
+
+macro_rules! impl_as_strftime_datetime {
+    ($ca:ty, $fun:ident) => {
+        impl AsStrftime for $ca {
+            fn as_strftime(&self, fmt: &str) -> Utf8Chunked {
+                ChunkedArray::new_from_opt_iter(
+                    self.name(),
+                    self.into_iter()
+                        .map(|opt_t| opt_t.and_then($fun).map(|dt| dt.format(fmt).to_string())),
+                )
+            }
+        }
+    };
+}
+
+impl_as_strftime_datetime!(Date32Chunked, date32_as_datetime);
+impl_as_strftime_datetime!(Date64Chunked, date64_as_datetime);
+impl_as_strftime_datetime!(
+    TimestampNanosecondChunked,
+    timestamp_nanoseconds_as_datetime
+);
+impl_as_strftime_datetime!(
+    TimestampMicrosecondChunked,
+    timestamp_microseconds_as_datetime
+);
+impl_as_strftime_datetime!(
+    TimestampMillisecondChunked,
+    timestamp_milliseconds_as_datetime
+);
+impl_as_strftime_datetime!(TimestampSecondChunked, timestamp_seconds_as_datetime);
+
+/// Localizes UTC instants (the values held by every `Timestamp*Chunked`) into the wall-clock
+/// `NaiveDateTime` of `tz`. The existing naive conversion is reused to get the UTC instant, and
+/// `DateTime::with_timezone` carries it to `tz`, so the caller's `tz` value is what carries the
+/// offset metadata needed for a lossless round-trip back through [`localize_naive_datetime_to_utc`].
+pub trait AsDatetimeInZone: AsNaiveDateTime {
+    fn as_naive_datetime_in_zone<Tz: TimeZone>(&self, tz: &Tz) -> Vec<Option<NaiveDateTime>> {
+        self.as_naive_datetime()
+            .into_iter()
+            .map(|opt_naive_utc| {
+                opt_naive_utc.map(|naive_utc| {
+                    DateTime::<Utc>::from_utc(naive_utc, Utc)
+                        .with_timezone(tz)
+                        .naive_local()
+                })
+            })
+            .collect()
+    }
+}
+
+impl<T: AsNaiveDateTime> AsDatetimeInZone for T {}
+
+/// Interprets each local (wall-clock) `NaiveDateTime` in `v` as being in `tz`, and resolves it to
+/// the corresponding UTC instant. A local time that falls in a DST gap (non-existent) or overlap
+/// (ambiguous) resolves to `None` rather than silently picking one of the candidate instants,
+/// matching `TimeZone::from_local_datetime`'s `LocalResult` contract.
+pub fn localize_naive_datetime_to_utc<Tz: TimeZone>(
+    v: &[NaiveDateTime],
+    tz: &Tz,
+) -> Vec<Option<NaiveDateTime>> {
+    v.iter()
+        .map(|naive_local| match tz.from_local_datetime(naive_local) {
+            LocalResult::Single(dt) => Some(dt.naive_utc()),
+            LocalResult::Ambiguous(_, _) | LocalResult::None => None,
+        })
+        .collect()
+}
+
 #[cfg(all(test, feature = "temporal"))]
 mod test {
+    use super::{localize_naive_datetime_to_utc, AsDatetimeInZone, AsStrftime};
     use crate::prelude::*;
     use chrono::{NaiveDateTime, NaiveTime};
 
@@ -428,4 +680,55 @@ mod test {
             ca.cont_slice().unwrap()
         );
     }
+
+    #[test]
+    fn roundtrip_strftime() {
+        let dates = &["2020-08-21", "2020-08-22"];
+        let fmt = "%Y-%m-%d";
+        let ca = Date32Chunked::parse_from_str_slice("dates", dates, fmt);
+        let back = ca.as_strftime(fmt);
+        assert_eq!(back.into_iter().collect::<Vec<_>>(), vec![
+            Some("2020-08-21"),
+            Some("2020-08-22")
+        ]);
+    }
+
+    #[test]
+    fn timezone_roundtrip() {
+        use chrono::FixedOffset;
+
+        let datetimes: Vec<_> = ["2015-09-05 23:56:04"]
+            .iter()
+            .map(|s| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap())
+            .collect();
+        let utc_ts = TimestampSecondChunked::new_from_naive_datetime("name", &datetimes);
+
+        let offset = FixedOffset::east(2 * 3600);
+        let local = utc_ts.as_naive_datetime_in_zone(&offset);
+        assert_eq!(
+            local[0].unwrap(),
+            NaiveDateTime::parse_from_str("2015-09-06 01:56:04", "%Y-%m-%d %H:%M:%S").unwrap()
+        );
+
+        let back_to_utc = localize_naive_datetime_to_utc(
+            &local.into_iter().map(Option::unwrap).collect::<Vec<_>>(),
+            &offset,
+        );
+        assert_eq!(back_to_utc[0].unwrap(), datetimes[0]);
+    }
+
+    #[test]
+    fn infer_date_format() {
+        let dates = &["2020-08-21", "2020-08-22"];
+        let (ca, fmt) = Date32Chunked::parse_from_str_slice_infer("dates", dates).unwrap();
+        assert_eq!(fmt, "%Y-%m-%d");
+        assert_eq!([18495, 18496], ca.cont_slice().unwrap());
+    }
+
+    #[test]
+    fn strict_parsing_reports_failures() {
+        let dates = &["2020-08-21", "not-a-date"];
+        let res = Date32Chunked::parse_from_str_slice_strict("dates", dates, "%Y-%m-%d");
+        assert!(res.is_err());
+    }
 }
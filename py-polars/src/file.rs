@@ -7,13 +7,19 @@ use pyo3::exceptions::PyTypeError;
 use pyo3::prelude::*;
 use pyo3::types::{PyBytes, PyString};
 use std::borrow::Borrow;
+use std::cell::RefCell;
 use std::fs::File;
 use std::io;
 use std::io::{Read, Seek, SeekFrom, Write};
+use std::os::unix::io::{FromRawFd, IntoRawFd, RawFd};
+use std::rc::Rc;
 
 #[derive(Clone)]
 pub struct PyFileLikeObject {
     inner: PyObject,
+    // Detected once at construction so the hot `read` path only pays for a bool check, not an
+    // attribute lookup, to decide between the `readinto` and `read` strategies.
+    has_readinto: bool,
 }
 
 /// Wraps a `PyObject`, and implements read, seek, and write for it.
@@ -22,7 +28,13 @@ impl PyFileLikeObject {
     /// To assert the object has the required methods methods,
     /// instantiate it with `PyFileLikeObject::require`
     pub fn new(object: PyObject) -> Self {
-        PyFileLikeObject { inner: object }
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        let has_readinto = object.getattr(py, "readinto").is_ok();
+        PyFileLikeObject {
+            inner: object,
+            has_readinto,
+        }
     }
 
     /// Same as `PyFileLikeObject::new`, but validates that the underlying
@@ -65,19 +77,90 @@ impl PyFileLikeObject {
     }
 }
 
-/// Extracts a string repr from, and returns an IO error to send back to rust.
+/// A Python exception preserved across the trip through `io::Error`: unlike a bare `__str__`,
+/// this keeps the exception's type name and traceback around for debugging.
+#[derive(Debug)]
+pub struct PyIoError {
+    type_name: String,
+    message: String,
+    traceback: Option<String>,
+}
+
+impl PyIoError {
+    /// The Python traceback (as formatted by `traceback.format_tb`), if one was available.
+    pub fn traceback(&self) -> Option<&str> {
+        self.traceback.as_deref()
+    }
+}
+
+impl std::fmt::Display for PyIoError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.type_name, self.message)
+    }
+}
+
+impl std::error::Error for PyIoError {}
+
+/// Extracts the type, message, and traceback from a Python exception, and returns an `io::Error`
+/// that preserves them (via [`PyIoError`]) instead of flattening everything to `__str__`. Maps
+/// well-known Python exception types to the matching `io::ErrorKind` so callers can match on kind
+/// rather than string-sniffing.
 fn pyerr_to_io_err(e: PyErr) -> io::Error {
     let gil = Python::acquire_gil();
     let py = gil.python();
-    let e_as_object: PyObject = e.into_py(py);
 
-    match e_as_object.call_method(py, "__str__", (), None) {
-        Ok(repr) => match repr.extract::<String>(py) {
-            Ok(s) => io::Error::new(io::ErrorKind::Other, s),
-            Err(_e) => io::Error::new(io::ErrorKind::Other, "An unknown error has occurred"),
+    let type_name = e
+        .ptype(py)
+        .getattr("__name__")
+        .ok()
+        .and_then(|name| name.extract::<String>().ok())
+        .unwrap_or_else(|| "Exception".to_string());
+
+    let message = e
+        .pvalue(py)
+        .str()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_else(|_| "An unknown error has occurred".to_string());
+
+    let traceback = e.ptraceback(py).and_then(|tb| {
+        py.import("traceback")
+            .and_then(|traceback_mod| traceback_mod.call_method1("format_tb", (tb,)))
+            .and_then(|lines| lines.extract::<Vec<String>>())
+            .ok()
+            .map(|lines| lines.join(""))
+    });
+
+    let kind = match type_name.as_str() {
+        "FileNotFoundError" => io::ErrorKind::NotFound,
+        "PermissionError" => io::ErrorKind::PermissionDenied,
+        "BlockingIOError" => io::ErrorKind::WouldBlock,
+        "EOFError" => io::ErrorKind::UnexpectedEof,
+        _ => io::ErrorKind::Other,
+    };
+
+    io::Error::new(
+        kind,
+        PyIoError {
+            type_name,
+            message,
+            traceback,
         },
-        Err(_) => io::Error::new(io::ErrorKind::Other, "Err doesn't have __str__"),
-    }
+    )
+}
+
+/// Wraps `buf` in a Python `memoryview` over the same memory, via the buffer protocol, so a
+/// Python-side `readinto` writes directly into it instead of allocating a fresh `bytes` object.
+///
+/// # Safety
+/// The returned memoryview must not outlive `buf`, and nothing else may access `buf` while the
+/// memoryview is alive.
+unsafe fn writable_memoryview_from_slice(py: Python, buf: &mut [u8]) -> PyObject {
+    let ptr = pyo3::ffi::PyMemoryView_FromMemory(
+        buf.as_mut_ptr() as *mut std::os::raw::c_char,
+        buf.len() as isize,
+        pyo3::ffi::PyBUF_WRITE,
+    );
+    PyObject::from_owned_ptr(py, ptr)
 }
 
 impl Read for PyFileLikeObject {
@@ -85,6 +168,17 @@ impl Read for PyFileLikeObject {
         let gil = Python::acquire_gil();
         let py = gil.python();
 
+        if self.has_readinto {
+            // SAFETY: the memoryview is only used for the duration of this `readinto` call and
+            // dropped before `buf` goes out of scope.
+            let view = unsafe { writable_memoryview_from_slice(py, buf) };
+            let n_read = self
+                .inner
+                .call_method(py, "readinto", (view,), None)
+                .map_err(pyerr_to_io_err)?;
+            return n_read.extract(py).map_err(pyerr_to_io_err);
+        }
+
         let bytes = self
             .inner
             .call_method(py, "read", (buf.len(),), None)
@@ -147,6 +241,99 @@ impl Seek for PyFileLikeObject {
     }
 }
 
+/// A `File` built from a file descriptor borrowed from a Python object (e.g. via `fileno()`), or
+/// from a `dup`-ed copy of one. The original fd is owned by Python, so `Drop` must not close it:
+/// we hand it back with `into_raw_fd()` instead of letting `File`'s own destructor run. A fd
+/// obtained via `try_clone` (`dup(2)`) is a distinct, genuinely-owned descriptor that Python knows
+/// nothing about, so it must be closed like any other `File` — `close_on_drop` tracks which case
+/// a given `RustFd` is in.
+pub struct RustFd {
+    file: Option<File>,
+    close_on_drop: bool,
+}
+
+impl RustFd {
+    /// # Safety
+    /// `fd` must be a valid, open file descriptor for the lifetime of the returned `RustFd`.
+    unsafe fn new(fd: RawFd) -> Self {
+        RustFd {
+            file: Some(File::from_raw_fd(fd)),
+            close_on_drop: false,
+        }
+    }
+
+    fn file_mut(&mut self) -> &mut File {
+        self.file.as_mut().expect("file taken only on drop")
+    }
+}
+
+impl Drop for RustFd {
+    fn drop(&mut self) {
+        if let Some(file) = self.file.take() {
+            if self.close_on_drop {
+                // An owned fd (e.g. from `try_clone`'s `dup(2)`): close it like any other `File`.
+                drop(file);
+            } else {
+                // Leave ownership of the borrowed fd with Python instead of closing it.
+                let _ = file.into_raw_fd();
+            }
+        }
+    }
+}
+
+impl Read for RustFd {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, io::Error> {
+        self.file_mut().read(buf)
+    }
+}
+
+impl Write for RustFd {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, io::Error> {
+        self.file_mut().write(buf)
+    }
+
+    fn flush(&mut self) -> Result<(), io::Error> {
+        self.file_mut().flush()
+    }
+}
+
+impl Seek for RustFd {
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64, io::Error> {
+        self.file_mut().seek(pos)
+    }
+}
+
+impl Length for RustFd {
+    fn len(&self) -> u64 {
+        self.file.as_ref().expect("file taken only on drop").len()
+    }
+}
+
+impl TryClone for RustFd {
+    fn try_clone(&self) -> std::result::Result<Self, ParquetError> {
+        let file = self.file.as_ref().expect("file taken only on drop");
+        let cloned = file
+            .try_clone()
+            .map_err(|e| ParquetError::General(e.to_string()))?;
+        // `File::try_clone` dup(2)s a brand-new fd that Python doesn't know about, so unlike the
+        // borrowed original, this one must be closed on drop.
+        Ok(RustFd {
+            file: Some(cloned),
+            close_on_drop: true,
+        })
+    }
+}
+
+/// Mirrors CPython's `PyObject_AsFileDescriptor`: accepts a bare `int`, or an object exposing a
+/// `fileno()` method that returns one.
+fn get_fileno(py_f: &PyObject, py: Python) -> Option<RawFd> {
+    if let Ok(fd) = py_f.extract::<RawFd>(py) {
+        return Some(fd);
+    }
+    let fileno = py_f.call_method0(py, "fileno").ok()?;
+    fileno.extract::<RawFd>(py).ok()
+}
+
 pub trait FileLike: Read + Write + Seek {}
 
 // Needed for arrow parquet
@@ -172,13 +359,316 @@ impl TryClone for PyFileLikeObject {
 
 impl FileLike for File {}
 impl FileLike for PyFileLikeObject {}
+impl FileLike for RustFd {}
+
+/// Shared state behind a `BufferedSeeklessReader`, held in an `Rc<RefCell<_>>` so that clones
+/// made via `TryClone` (as parquet does routinely per row-group/column) observe the same cache
+/// and position as the original, matching how cloning a `PyFileLikeObject` shares the same
+/// underlying Python file object and its single position.
+struct BufferedSeeklessReaderState {
+    inner: PyFileLikeObject,
+    cache: Vec<u8>,
+    pos: usize,
+    eof: bool,
+}
+
+impl BufferedSeeklessReaderState {
+    fn fill_to(&mut self, target: usize) -> io::Result<()> {
+        while self.cache.len() < target && !self.eof {
+            let want = (target - self.cache.len()).max(64 * 1024);
+            let mut chunk = vec![0u8; want];
+            let n = self.inner.read(&mut chunk)?;
+            if n == 0 {
+                self.eof = true;
+                break;
+            }
+            chunk.truncate(n);
+            self.cache.extend_from_slice(&chunk);
+        }
+        Ok(())
+    }
+
+    fn fill_all(&mut self) -> io::Result<()> {
+        while !self.eof {
+            let target = self.cache.len() + 64 * 1024;
+            self.fill_to(target)?;
+        }
+        Ok(())
+    }
+}
+
+/// Gives a forward-only `PyFileLikeObject` (no native `seek`) a working `Seek` by caching every
+/// byte read so far. Seeking within the cached region is free; seeking past the end of the cache
+/// transparently reads forward to fill the gap. `write` is passed straight through, since
+/// buffering only needs to help the read side.
+pub struct BufferedSeeklessReader {
+    state: Rc<RefCell<BufferedSeeklessReaderState>>,
+}
+
+impl BufferedSeeklessReader {
+    fn new(inner: PyFileLikeObject) -> Self {
+        BufferedSeeklessReader {
+            state: Rc::new(RefCell::new(BufferedSeeklessReaderState {
+                inner,
+                cache: Vec::new(),
+                pos: 0,
+                eof: false,
+            })),
+        }
+    }
+}
+
+impl Read for BufferedSeeklessReader {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, io::Error> {
+        let mut state = self.state.borrow_mut();
+        state.fill_to(state.pos + buf.len())?;
+        let end = state.cache.len().min(state.pos + buf.len());
+        let n = end - state.pos;
+        buf[..n].copy_from_slice(&state.cache[state.pos..end]);
+        state.pos += n;
+        Ok(n)
+    }
+}
+
+impl Write for BufferedSeeklessReader {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, io::Error> {
+        self.state.borrow_mut().inner.write(buf)
+    }
+
+    fn flush(&mut self) -> Result<(), io::Error> {
+        self.state.borrow_mut().inner.flush()
+    }
+}
+
+impl Seek for BufferedSeeklessReader {
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64, io::Error> {
+        let mut state = self.state.borrow_mut();
+
+        let target = match pos {
+            SeekFrom::Start(offset) => offset as i64,
+            SeekFrom::Current(offset) => state.pos as i64 + offset,
+            SeekFrom::End(offset) => {
+                state.fill_all()?;
+                state.cache.len() as i64 + offset
+            }
+        };
+
+        if target < 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "cannot seek to a negative position",
+            ));
+        }
+
+        let target = target as usize;
+        state.fill_to(target)?;
+        state.pos = target.min(state.cache.len());
+        Ok(state.pos as u64)
+    }
+}
+
+impl FileLike for BufferedSeeklessReader {}
+
+impl Length for BufferedSeeklessReader {
+    fn len(&self) -> u64 {
+        let mut state = self.state.borrow_mut();
+        state
+            .fill_all()
+            .expect("failed to read underlying stream to determine its length");
+        state.cache.len() as u64
+    }
+}
+
+impl TryClone for BufferedSeeklessReader {
+    fn try_clone(&self) -> std::result::Result<Self, ParquetError> {
+        Ok(BufferedSeeklessReader {
+            state: Rc::clone(&self.state),
+        })
+    }
+}
+
+/// Default size of the large, infrequent Python-side `read`/`write` calls `BufferedPyFile` makes.
+/// Within the 1-8 MiB range that amortizes GIL acquisition and method dispatch cost over a
+/// realistic parquet row-group scan without holding an unreasonable amount of data in memory.
+const DEFAULT_PY_BUFFER_SIZE: usize = 4 * 1024 * 1024;
+
+/// Shared state behind a `BufferedPyFile`, held in an `Rc<RefCell<_>>` so that clones made via
+/// `TryClone` (as parquet does routinely per row-group/column) observe the same buffers and
+/// position as the original, matching how cloning a `PyFileLikeObject` shares the same underlying
+/// Python file object and its single position.
+struct BufferedPyFileState {
+    inner: PyFileLikeObject,
+    buffer_size: usize,
+    read_buf: Vec<u8>,
+    read_pos: usize,
+    write_buf: Vec<u8>,
+}
+
+impl BufferedPyFileState {
+    fn refill(&mut self) -> io::Result<()> {
+        self.read_buf.resize(self.buffer_size, 0);
+        let n = self.inner.read(&mut self.read_buf)?;
+        self.read_buf.truncate(n);
+        self.read_pos = 0;
+        Ok(())
+    }
+
+    /// Number of bytes already prefetched into `read_buf` but not yet handed to a caller. The
+    /// underlying Python stream's cursor sits this many bytes ahead of our logical position.
+    fn unread(&self) -> usize {
+        self.read_buf.len() - self.read_pos
+    }
+
+    /// Rewinds the underlying stream's cursor back to our logical position, undoing the
+    /// read-ahead performed by `refill`, and discards the now-stale read buffer. Must run before
+    /// any write so the write lands at the logical position instead of wherever read-ahead left
+    /// the Python cursor.
+    fn discard_read_buf(&mut self) -> io::Result<()> {
+        if self.unread() > 0 {
+            self.inner.seek(SeekFrom::Current(-(self.unread() as i64)))?;
+        }
+        self.read_buf.clear();
+        self.read_pos = 0;
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if !self.write_buf.is_empty() {
+            self.inner.write_all(&self.write_buf)?;
+            self.write_buf.clear();
+        }
+        self.inner.flush()
+    }
+}
+
+/// Wraps a `PyFileLikeObject` so that small, frequent Rust-side reads/writes are served out of an
+/// internal buffer instead of each making its own GIL-acquiring Python call. Large (by default
+/// 4 MiB) `read`/`write` calls refill/drain that buffer under a single GIL acquisition. `flush`
+/// (and `Drop`) push any buffered writes out; `seek` reconciles the buffer's position against the
+/// underlying stream before delegating.
+pub struct BufferedPyFile {
+    state: Rc<RefCell<BufferedPyFileState>>,
+}
+
+impl BufferedPyFile {
+    pub fn new(inner: PyFileLikeObject) -> Self {
+        Self::with_capacity(inner, DEFAULT_PY_BUFFER_SIZE)
+    }
+
+    pub fn with_capacity(inner: PyFileLikeObject, buffer_size: usize) -> Self {
+        BufferedPyFile {
+            state: Rc::new(RefCell::new(BufferedPyFileState {
+                inner,
+                buffer_size,
+                read_buf: Vec::new(),
+                read_pos: 0,
+                write_buf: Vec::new(),
+            })),
+        }
+    }
+}
+
+impl Read for BufferedPyFile {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, io::Error> {
+        let mut state = self.state.borrow_mut();
+        if state.unread() == 0 {
+            state.refill()?;
+            if state.read_buf.is_empty() {
+                return Ok(0);
+            }
+        }
+        let available = &state.read_buf[state.read_pos..];
+        let n = available.len().min(buf.len());
+        buf[..n].copy_from_slice(&available[..n]);
+        state.read_pos += n;
+        Ok(n)
+    }
+}
+
+impl Write for BufferedPyFile {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, io::Error> {
+        let mut state = self.state.borrow_mut();
+        state.discard_read_buf()?;
+        state.write_buf.extend_from_slice(buf);
+        if state.write_buf.len() >= state.buffer_size {
+            state.flush()?;
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> Result<(), io::Error> {
+        self.state.borrow_mut().flush()
+    }
+}
+
+impl Seek for BufferedPyFile {
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64, io::Error> {
+        let mut state = self.state.borrow_mut();
+        state.flush()?;
+
+        // Rewind the underlying stream's read-ahead-advanced cursor back to our logical
+        // position before seeking, so `SeekFrom::Current` (and the other variants) apply
+        // relative to where the caller thinks we are, not wherever read-ahead left the cursor.
+        state.discard_read_buf()?;
+
+        state.inner.seek(pos)
+    }
+}
+
+impl FileLike for BufferedPyFile {}
+
+impl Length for BufferedPyFile {
+    fn len(&self) -> u64 {
+        self.state.borrow().inner.len()
+    }
+}
+
+impl TryClone for BufferedPyFile {
+    fn try_clone(&self) -> std::result::Result<Self, ParquetError> {
+        Ok(BufferedPyFile {
+            state: Rc::clone(&self.state),
+        })
+    }
+}
+
+impl Drop for BufferedPyFile {
+    fn drop(&mut self) {
+        // Only the last clone sharing this buffer should push out pending writes; earlier drops
+        // while other clones are still alive would flush a write_buf that isn't actually done.
+        if Rc::strong_count(&self.state) == 1 {
+            let _ = self.state.borrow_mut().flush();
+        }
+    }
+}
 
 pub enum EitherRustPythonFile {
-    Py(PyFileLikeObject),
+    Py(BufferedPyFile),
     Rust(File),
+    RustFd(RustFd),
+    PyBuffered(BufferedSeeklessReader),
 }
 
+/// `truncate` already tells us which direction the caller needs: opening for write (`truncate`)
+/// never also needs read, and opening for read (`!truncate`) never also needs write. Deriving
+/// `read`/`write` from it means a read-only stream (an HTTP response body, a pipe, `io.BytesIO`
+/// opened for reading) passed in for a read (`truncate = false`) is no longer rejected for lacking
+/// a `write` method it was never going to be asked to use.
 pub fn get_either_file(py_f: PyObject, truncate: bool) -> PyResult<EitherRustPythonFile> {
+    get_either_file_with_access(py_f, truncate, !truncate, truncate, true)
+}
+
+/// Like `get_either_file`, but only asserts the methods actually needed for `read`/`write`/`seek`
+/// instead of unconditionally requiring all three. A read-only, non-seekable stream (an HTTP
+/// response body, a pipe, `io.BytesIO` opened for reading) can be used for read-only,
+/// non-seeking work this way; if `seek` is requested but the object can't seek natively, it's
+/// wrapped in a [`BufferedSeeklessReader`] that emulates it.
+pub fn get_either_file_with_access(
+    py_f: PyObject,
+    truncate: bool,
+    read: bool,
+    write: bool,
+    seek: bool,
+) -> PyResult<EitherRustPythonFile> {
     let gil = Python::acquire_gil();
     let py = gil.python();
 
@@ -191,16 +681,47 @@ pub fn get_either_file(py_f: PyObject, truncate: bool) -> PyResult<EitherRustPyt
             File::open(str_slice)?
         };
         Ok(EitherRustPythonFile::Rust(f))
+    } else if let Some(fd) = get_fileno(&py_f, py) {
+        // SAFETY: `fd` was just obtained from a live Python object's `fileno()`/`int`, so it is
+        // open for at least as long as that object is. `RustFd` never closes it on drop.
+        Ok(EitherRustPythonFile::RustFd(unsafe { RustFd::new(fd) }))
+    } else if seek {
+        match PyFileLikeObject::with_requirements(py_f.clone_ref(py), read, write, true) {
+            Ok(f) => Ok(EitherRustPythonFile::Py(BufferedPyFile::new(f))),
+            Err(_) => {
+                let f = PyFileLikeObject::with_requirements(py_f, read, write, false)?;
+                Ok(EitherRustPythonFile::PyBuffered(BufferedSeeklessReader::new(
+                    f,
+                )))
+            }
+        }
     } else {
-        let f = PyFileLikeObject::with_requirements(py_f, true, true, true)?;
-        Ok(EitherRustPythonFile::Py(f))
+        let f = PyFileLikeObject::with_requirements(py_f, read, write, false)?;
+        Ok(EitherRustPythonFile::Py(BufferedPyFile::new(f)))
     }
 }
 
+/// See [`get_either_file`]'s doc comment: `read`/`write` are derived from `truncate` rather than
+/// hardcoded, so this entry point is actually reachable for read-only/non-seekable streams too.
 pub fn get_file_like(f: PyObject, truncate: bool) -> PyResult<Box<dyn FileLike>> {
+    get_file_like_with_access(f, truncate, !truncate, truncate, true)
+}
+
+/// Like `get_file_like`, but threads the intended access mode through to
+/// `get_either_file_with_access` so a read-only or non-seekable stream isn't rejected for
+/// capabilities the caller never needed.
+pub fn get_file_like_with_access(
+    f: PyObject,
+    truncate: bool,
+    read: bool,
+    write: bool,
+    seek: bool,
+) -> PyResult<Box<dyn FileLike>> {
     use EitherRustPythonFile::*;
-    match get_either_file(f, truncate)? {
+    match get_either_file_with_access(f, truncate, read, write, seek)? {
         Py(f) => Ok(Box::new(f)),
         Rust(f) => Ok(Box::new(f)),
+        RustFd(f) => Ok(Box::new(f)),
+        PyBuffered(f) => Ok(Box::new(f)),
     }
 }